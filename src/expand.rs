@@ -3,13 +3,16 @@ use crate::parse::Item;
 use crate::receiver::{ mut_pat, has_self_in_block, has_self_in_sig, ReplaceSelf};
 use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned, ToTokens};
+use std::collections::HashSet;
 use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
+use syn::parse::ParseStream;
+use syn::visit::{self, Visit};
 use syn::visit_mut::VisitMut;
 use syn::{
-    parse_quote, Block, FnArg, GenericParam, Generics, Ident, ImplItem, Lifetime, Pat, PatIdent,
-    Receiver, ReturnType, Signature, Stmt, Token, TraitItem, Type, TypeParamBound,
-    WhereClause,
+    parse_quote, Attribute, Block, FnArg, GenericParam, Generics, Ident, ImplItem, Lifetime, Pat,
+    PatIdent, Receiver, ReturnType, Signature, Stmt, Token, TraitItem, Type, TypeParamBound,
+    TypeReference, WhereClause,
 };
 
 impl ToTokens for Item {
@@ -50,7 +53,231 @@ impl Context<'_> {
 
 type Supertraits = Punctuated<TypeParamBound, Token![+]>;
 
-pub fn expand(input: &mut Item, is_local: bool) {
+// Everything the macro itself needs to name: the elided-lifetime prefixes
+// handed to `CollectLifetimes`, the `'async_trait` lifetime bounding the
+// returned future, and the synthetic bindings introduced in the desugared
+// body. Instead of hardcoding these, `Names::new` walks the user's trait or
+// impl first and nudges any name that would collide, the same trick
+// pin-project's `determine_lifetime_name` uses to stay out of the way of
+// whatever the user already wrote.
+struct Names {
+    life_prefix: String,
+    impl_prefix: String,
+    async_trait_lifetime: Lifetime,
+    self_prefix: String,
+    ret_ident: Ident,
+    sentinel_ident: Ident,
+    arg_prefix: String,
+}
+
+impl Names {
+    fn new(item: &Item) -> Self {
+        let mut used = UsedNames::default();
+        let mut arity = 0;
+
+        // Visit every trait/impl item in full, not just each method's
+        // `Signature` — a name introduced only inside a method's default
+        // body (or inside some other associated item) is just as visible to
+        // the user as one in the signature, and must dodge collisions too.
+        match item {
+            Item::Trait(item) => {
+                used.visit_generics(&item.generics);
+                for supertrait in &item.supertraits {
+                    used.visit_type_param_bound(supertrait);
+                }
+                for inner in &item.items {
+                    used.visit_trait_item(inner);
+                    if let TraitItem::Method(method) = inner {
+                        arity = arity.max(method.sig.inputs.len());
+                    }
+                }
+            }
+            Item::Impl(item) => {
+                used.visit_generics(&item.generics);
+                used.visit_type(&item.self_ty);
+                if let Some((_, path, _)) = &item.trait_ {
+                    used.visit_path(path);
+                }
+                for inner in &item.items {
+                    used.visit_impl_item(inner);
+                    if let ImplItem::Method(method) = inner {
+                        arity = arity.max(method.sig.inputs.len());
+                    }
+                }
+            }
+        }
+
+        // `CollectLifetimes` numbers every elided lifetime it's handed
+        // starting at 0, so a bound of `elided_lifetime_positions` covers
+        // the worst case for any single call site even though `Names::new`
+        // only sees the aggregate across the whole item.
+        let worst_case_lifetimes = used.elided_lifetime_positions.max(1);
+        let life_prefix = format!(
+            "'{}",
+            unique_numbered_prefix("life", &used.lifetimes, worst_case_lifetimes)
+        );
+        let impl_prefix = format!(
+            "'{}",
+            unique_numbered_prefix("impl", &used.lifetimes, worst_case_lifetimes)
+        );
+        let async_trait_name = unique_base("async_trait", &used.lifetimes);
+        let async_trait_lifetime =
+            Lifetime::new(&format!("'{}", async_trait_name), Span::call_site());
+
+        let self_prefix = unique_self_prefix(&used.idents);
+        let ret_ident = Ident::new(&unique_base("__ret", &used.idents), Span::call_site());
+        let sentinel_ident = Ident::new(
+            &unique_base("__async_trait", &used.idents),
+            Span::call_site(),
+        );
+        let arg_prefix = unique_arg_prefix(&used.idents, arity);
+
+        Names {
+            life_prefix,
+            impl_prefix,
+            async_trait_lifetime,
+            self_prefix,
+            ret_ident,
+            sentinel_ident,
+            arg_prefix,
+        }
+    }
+
+    fn positional_arg(&self, i: usize, span: Span) -> Ident {
+        format_ident!("{}{}", self.arg_prefix, i, span = span)
+    }
+}
+
+// Collects every lifetime and plain identifier already visible in the
+// user's trait or impl, so `Names::new` can pick generated names that don't
+// shadow or collide with them. Also counts every elided (unnamed) lifetime
+// position — `&T` and the `&self`/`&mut self` receiver — since each one of
+// those is exactly what `CollectLifetimes` assigns a fresh numbered name
+// (`'life0`, `'life1`, ...), so that family, not just the bare prefix, has
+// to be checked for collisions.
+#[derive(Default)]
+struct UsedNames {
+    lifetimes: HashSet<String>,
+    idents: HashSet<String>,
+    elided_lifetime_positions: usize,
+}
+
+impl<'ast> Visit<'ast> for UsedNames {
+    fn visit_lifetime(&mut self, lifetime: &'ast Lifetime) {
+        self.lifetimes.insert(lifetime.ident.to_string());
+    }
+
+    fn visit_ident(&mut self, ident: &'ast Ident) {
+        self.idents.insert(ident.to_string());
+    }
+
+    fn visit_type_reference(&mut self, ty: &'ast TypeReference) {
+        if is_elided_lifetime(&ty.lifetime) {
+            self.elided_lifetime_positions += 1;
+        }
+        visit::visit_type_reference(self, ty);
+    }
+
+    fn visit_receiver(&mut self, receiver: &'ast Receiver) {
+        if let Some((_, lifetime)) = &receiver.reference {
+            if is_elided_lifetime(lifetime) {
+                self.elided_lifetime_positions += 1;
+            }
+        }
+        visit::visit_receiver(self, receiver);
+    }
+}
+
+// `'_` is the "placeholder" lifetime: written explicitly but, like full
+// elision, it still tells the compiler to pick a fresh lifetime rather than
+// naming an existing one. `CollectLifetimes` numbers both forms the same
+// way, so they must count as the same kind of position here too.
+fn is_elided_lifetime(lifetime: &Option<Lifetime>) -> bool {
+    match lifetime {
+        None => true,
+        Some(lifetime) => lifetime.ident == "_",
+    }
+}
+
+// Picks `base`, or `base` with an incrementing numeric suffix, whichever is
+// the first that isn't already taken. `'async_trait` falls back to
+// `'async_trait1`, `'async_trait2`, and so on.
+fn unique_base(base: &str, taken: &HashSet<String>) -> String {
+    if !taken.contains(base) {
+        return base.to_string();
+    }
+    let mut suffix = 1;
+    loop {
+        let candidate = format!("{}{}", base, suffix);
+        if !taken.contains(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+// Like `unique_base`, but for a prefix that `CollectLifetimes` expands into
+// a numbered family (`'life0`, `'life1`, ...) rather than using verbatim:
+// the whole family up to `worst_case_count` has to be collision-free, not
+// just the bare word, or a user lifetime like an explicit `'life0` slips
+// through untouched while the macro goes on to mint its own `'life0`.
+fn unique_numbered_prefix(base: &str, taken: &HashSet<String>, worst_case_count: usize) -> String {
+    let collides = |candidate: &str| {
+        taken.contains(candidate)
+            || (0..worst_case_count).any(|i| taken.contains(&format!("{}{}", candidate, i)))
+    };
+
+    if !collides(base) {
+        return base.to_string();
+    }
+    let mut suffix = 1;
+    loop {
+        let candidate = format!("{}{}", base, suffix);
+        if !collides(&candidate) {
+            return candidate;
+        }
+        suffix += 1;
+    }
+}
+
+// `__self` is glued together from a prefix and the literal word `self` (see
+// `transform_block` and `receiver::ReplaceSelf`), so instead of a numeric
+// suffix we grow the prefix itself until the result is free.
+fn unique_self_prefix(idents: &HashSet<String>) -> String {
+    let mut prefix = String::from("__");
+    while idents.contains(&format!("{}self", prefix)) {
+        prefix.push('_');
+    }
+    prefix
+}
+
+// `__arg{i}` likewise glues a prefix onto each positional index; grow the
+// prefix until none of the indices in use by this item collide.
+fn unique_arg_prefix(idents: &HashSet<String>, arity: usize) -> String {
+    let mut prefix = String::from("__arg");
+    while (0..arity).any(|i| idents.contains(&format!("{}{}", prefix, i))) {
+        prefix.push('_');
+    }
+    prefix
+}
+
+// `extra_bounds` is the set of additional auto-trait marker idents parsed
+// out of the attribute by `parse.rs`, e.g. `Sync` for
+// `#[async_trait(Send + Sync)]`. A `Send` in the list is ignored by
+// `transform_sig`, since the `Send`/`?Send` choice is carried by `is_local`
+// (and a method-level override) alone. Every other marker is added both to
+// the `dyn Future` object in the return type and, where applicable, to the `Self:`
+// bound, so a consumer that needs the boxed future to be `Sync` as well as
+// `Send` has a way to ask for it.
+//
+// `container` is the smart pointer used to hold the pinned future, e.g.
+// `::std::boxed::Box` by default, or whatever `parse.rs` parsed out of
+// `#[async_trait(boxed_in = my_crate::Bump)]`. It must expose a `pin`
+// associated function with the same signature as `Box::pin`, since that's
+// what `transform_block` calls to build the future, and is used verbatim as
+// the pointer type wrapped in `Pin` in the generated signature.
+pub fn expand(input: &mut Item, is_local: bool, extra_bounds: &[Ident], container: &syn::Path) {
+    let names = Names::new(input);
     match input {
         Item::Trait(input) => {
             let context = Context::Trait {
@@ -61,24 +288,34 @@ pub fn expand(input: &mut Item, is_local: bool) {
                 if let TraitItem::Method(method) = inner {
                     let sig = &mut method.sig;
                     if sig.asyncness.is_some() {
+                        let method_is_local = is_local || take_local_override(&mut method.attrs);
                         let block = &mut method.default;
                         let mut has_self = has_self_in_sig(sig);
                         if let Some(block) = block {
                             has_self |= has_self_in_block(block);
-                            transform_block(sig, block);
+                            transform_block(sig, block, &names, container);
                             method
                                 .attrs
                                 .push(parse_quote!(#[allow(clippy::used_underscore_binding)]));
                         }
                         let has_default = method.default.is_some();
-                        transform_sig(context, sig, has_self, has_default, is_local);
+                        transform_sig(
+                            context,
+                            sig,
+                            has_self,
+                            has_default,
+                            method_is_local,
+                            &names,
+                            extra_bounds,
+                            container,
+                        );
                         method.attrs.push(parse_quote!(#[must_use]));
                     }
                 }
             }
         }
         Item::Impl(input) => {
-            let mut lifetimes = CollectLifetimes::new("'impl");
+            let mut lifetimes = CollectLifetimes::new(&names.impl_prefix);
             lifetimes.visit_type_mut(&mut *input.self_ty);
             lifetimes.visit_path_mut(&mut input.trait_.as_mut().unwrap().1);
             let params = &input.generics.params;
@@ -92,10 +329,20 @@ pub fn expand(input: &mut Item, is_local: bool) {
                 if let ImplItem::Method(method) = inner {
                     let sig = &mut method.sig;
                     if sig.asyncness.is_some() {
+                        let method_is_local = is_local || take_local_override(&mut method.attrs);
                         let block = &mut method.block;
                         let has_self = has_self_in_sig(sig) || has_self_in_block(block);
-                        transform_block(sig, block);
-                        transform_sig(context, sig, has_self, false, is_local);
+                        transform_block(sig, block, &names, container);
+                        transform_sig(
+                            context,
+                            sig,
+                            has_self,
+                            false,
+                            method_is_local,
+                            &names,
+                            extra_bounds,
+                            container,
+                        );
                         method
                             .attrs
                             .push(parse_quote!(#[allow(clippy::used_underscore_binding)]));
@@ -119,13 +366,28 @@ pub fn expand(input: &mut Item, is_local: bool) {
 //         'life1: 'async_trait,
 //         T: 'async_trait,
 //         Self: Sync + 'async_trait;
+//
+// (the `'life`/`'async_trait` names above are the common case; `Names::new`
+// picks different ones when those already appear in the user's source, and
+// `Box` above is whatever `container` was configured to)
 fn transform_sig(
     context: Context,
     sig: &mut Signature,
     has_self: bool,
     has_default: bool,
     is_local: bool,
+    names: &Names,
+    extra_bounds: &[Ident],
+    container: &syn::Path,
 ) {
+    // `Send` is never added from here: the non-local branch below hardcodes
+    // it itself, and the local branch (including a method-level `?Send`
+    // override that flips `is_local` for just this one method) must not
+    // have it silently added back.
+    let extra_bounds: Vec<&Ident> = extra_bounds
+        .iter()
+        .filter(|bound| *bound != "Send")
+        .collect();
     sig.fn_token.span = sig.asyncness.take().unwrap().span;
 
     let ret = match &sig.output {
@@ -133,7 +395,7 @@ fn transform_sig(
         ReturnType::Type(_, ret) => quote!(#ret),
     };
 
-    let mut lifetimes = CollectLifetimes::new("'life");
+    let mut lifetimes = CollectLifetimes::new(&names.life_prefix);
     for arg in sig.inputs.iter_mut() {
         match arg {
             FnArg::Receiver(arg) => lifetimes.visit_receiver_mut(arg),
@@ -141,6 +403,8 @@ fn transform_sig(
         }
     }
 
+    let async_trait_lifetime = &names.async_trait_lifetime;
+
     let where_clause = sig
         .generics
         .where_clause
@@ -159,13 +423,13 @@ fn transform_sig(
                 let param = &param.ident;
                 where_clause
                     .predicates
-                    .push(parse_quote!(#param: 'async_trait));
+                    .push(parse_quote!(#param: #async_trait_lifetime));
             }
             GenericParam::Lifetime(param) => {
                 let param = &param.lifetime;
                 where_clause
                     .predicates
-                    .push(parse_quote!(#param: 'async_trait));
+                    .push(parse_quote!(#param: #async_trait_lifetime));
             }
             GenericParam::Const(_) => {}
         }
@@ -174,9 +438,9 @@ fn transform_sig(
         sig.generics.params.push(parse_quote!(#elided));
         where_clause
             .predicates
-            .push(parse_quote!(#elided: 'async_trait));
+            .push(parse_quote!(#elided: #async_trait_lifetime));
     }
-    sig.generics.params.push(parse_quote!('async_trait));
+    sig.generics.params.push(parse_quote!(#async_trait_lifetime));
     if has_self {
         let bound: Ident = match sig.inputs.iter().next() {
             Some(FnArg::Receiver(Receiver {
@@ -196,14 +460,28 @@ fn transform_sig(
             }
             _ => parse_quote!(Send),
         };
-        let assume_bound = match context {
-            Context::Trait { supertraits, .. } => !has_default || has_bound(supertraits, &bound),
+        // Each requested marker (the primary `bound` plus every one of
+        // `extra_bounds`) is checked for supertrait coverage independently:
+        // a default method whose supertraits happen to cover `Send` but not
+        // a requested `Sync` must still get `Self: Sync` strengthened in,
+        // even though the `Send` half is already assumed.
+        let bound_is_assumed = |marker: &Ident| match context {
+            Context::Trait { supertraits, .. } => !has_default || has_bound(supertraits, marker),
             Context::Impl { .. } => true,
         };
-        where_clause.predicates.push(if assume_bound || is_local {
-            parse_quote!(Self: 'async_trait)
+        let mut needed_bounds = Vec::new();
+        if !is_local {
+            let mut seen = HashSet::new();
+            for marker in std::iter::once(&bound).chain(extra_bounds.iter().copied()) {
+                if !bound_is_assumed(marker) && seen.insert(marker.to_string()) {
+                    needed_bounds.push(marker);
+                }
+            }
+        }
+        where_clause.predicates.push(if needed_bounds.is_empty() {
+            parse_quote!(Self: #async_trait_lifetime)
         } else {
-            parse_quote!(Self: ::core::marker::#bound + 'async_trait)
+            parse_quote!(Self: #(::core::marker::#needed_bounds +)* #async_trait_lifetime)
         });
     }
 
@@ -219,7 +497,7 @@ fn transform_sig(
                     ident.mutability = None;
                 } else {
                     let span = arg.pat.span();
-                    let positional = positional_arg(i, span);
+                    let positional = names.positional_arg(i, span);
                     let m = mut_pat(&mut arg.pat);
                     arg.pat = parse_quote!(#m #positional);
                 }
@@ -228,13 +506,13 @@ fn transform_sig(
     }
 
     let bounds = if is_local {
-        quote!('async_trait)
+        quote!(#(::core::marker::#extra_bounds +)* #async_trait_lifetime)
     } else {
-        quote!(::core::marker::Send + 'async_trait)
+        quote!(::core::marker::Send #(+ ::core::marker::#extra_bounds)* + #async_trait_lifetime)
     };
 
     sig.output = parse_quote! {
-        -> ::core::pin::Pin<Box<
+        -> ::core::pin::Pin<#container<
             dyn ::core::future::Future<Output = #ret> + #bounds
         >>
     };
@@ -252,14 +530,32 @@ fn transform_sig(
 //             let x = x;
 //             let (a, b) = __arg1;
 //
+//             let __async_trait: ();
+//
 //             __self + x + a + b
 //         };
 //
 //         ___ret
 //     })
+//
+// Sentinel contract for downstream attribute macros (tracing::instrument,
+// logcall, and similar): the statement immediately after the argument
+// rebindings and before any of the user's own statements is always a
+// zero-sized `let <ident>: ();` binding, even when the method body is a
+// single expression or is empty. `<ident>` is `__async_trait` unless that
+// name collides with something in the user's source, in which case it gets
+// the same numeric-suffix treatment as `'async_trait` and `__ret` (see
+// `Names::new`). A macro that wants to locate the user's original async
+// block should look for the first `let _: ();` statement in the
+// surrounding block, then treat everything after it as the unmodified
+// method body, rather than matching the literal identifier `__async_trait`.
+// This holds regardless of whether `#[async_trait]` is applied above or
+// below the instrumentation macro.
 fn transform_block(
     sig: &mut Signature,
     block: &mut Block,
+    names: &Names,
+    container: &syn::Path,
 ) {
     if let Some(Stmt::Item(syn::Item::Verbatim(item))) = block.stmts.first() {
         if block.stmts.len() == 1 && item.to_string() == ";" {
@@ -267,7 +563,7 @@ fn transform_block(
         }
     }
 
-    let self_prefix = "__";
+    let self_prefix = names.self_prefix.as_str();
     let mut self_span = None;
     let decls = sig.inputs.iter().enumerate().map(|(i, arg)| match arg {
         FnArg::Receiver(Receiver { self_token, mutability, .. }) => {
@@ -287,7 +583,7 @@ fn transform_block(
                 }
             } else {
                 let pat = &arg.pat;
-                let ident = positional_arg(i, pat.span());
+                let ident = names.positional_arg(i, pat.span());
                 quote!(let #pat = #ident;)
             }
         }
@@ -304,24 +600,55 @@ fn transform_block(
         ReturnType::Type(_, ret) => quote!(#ret),
     };
 
+    let ret_ident = &names.ret_ident;
+    let sentinel_ident = &names.sentinel_ident;
     let box_pin = quote_spanned!(ret_ty.span()=>
-        Box::pin(async move {
-            let __ret: #ret_ty = {
+        #container::pin(async move {
+            let #ret_ident: #ret_ty = {
                 #(#decls)*
-                let __async_trait: ();
+                let #sentinel_ident: ();
                 #(#stmts)*
             };
 
             #[allow(unreachable_code)]
-            __ret
+            #ret_ident
         })
     );
 
     block.stmts = parse_quote!(#box_pin);
 }
 
-fn positional_arg(i: usize, span: Span) -> Ident {
-    format_ident!("__arg{}", i, span = span)
+// A method-level `#[async_trait(?Send)]` or `#[not_send]` attribute, found
+// and stripped from `attrs`. Unlike the crate-level `?Send` on the trait or
+// impl itself, this only flips `is_local` for the one method it's attached
+// to, so a trait can keep the strict `Send` bound on every method except
+// the one capturing an `Rc` or other non-`Send` type.
+fn take_local_override(attrs: &mut Vec<Attribute>) -> bool {
+    let mut found = false;
+    attrs.retain(|attr| {
+        if attr.path.is_ident("not_send") {
+            found = true;
+            return false;
+        }
+        if attr.path.is_ident("async_trait") && parses_as_question_send(attr) {
+            found = true;
+            return false;
+        }
+        true
+    });
+    found
+}
+
+fn parses_as_question_send(attr: &Attribute) -> bool {
+    attr.parse_args_with(|input: ParseStream| {
+        input.parse::<Token![?]>()?;
+        let ident: Ident = input.parse()?;
+        if ident != "Send" {
+            return Err(syn::Error::new(ident.span(), "expected `Send`"));
+        }
+        Ok(())
+    })
+    .is_ok()
 }
 
 fn has_bound(supertraits: &Supertraits, marker: &Ident) -> bool {
@@ -334,3 +661,137 @@ fn has_bound(supertraits: &Supertraits, marker: &Ident) -> bool {
     }
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Regression test for the collision pre-pass: a name that only ever
+    // appears inside a method's body (as opposed to its signature) still
+    // needs to be visible to `Names::new`, or `self_prefix`/`arg_prefix`
+    // won't grow to dodge it and the generated `let __self = self;` ends up
+    // shadowed by the user's own `let __self = ...;`.
+    #[test]
+    fn used_names_sees_identifiers_introduced_only_in_a_method_body() {
+        let block: Block = parse_quote! {
+            {
+                let __self = 42;
+                self.0 + __self
+            }
+        };
+
+        let mut used = UsedNames::default();
+        used.visit_block(&block);
+
+        assert!(used.idents.contains("__self"));
+    }
+
+    #[test]
+    fn self_prefix_grows_until_it_stops_colliding() {
+        let mut idents = HashSet::new();
+        idents.insert("__self".to_string());
+        idents.insert("___self".to_string());
+
+        let prefix = unique_self_prefix(&idents);
+
+        assert_eq!(prefix, "____");
+        assert!(!idents.contains(&format!("{}self", prefix)));
+    }
+
+    // Regression test for the exact scenario the request calls out: a user
+    // lifetime named `'life0` must push the generated prefix past the whole
+    // numbered family `CollectLifetimes` would otherwise mint, not just the
+    // bare word `life`.
+    #[test]
+    fn life_prefix_checks_the_whole_numbered_family() {
+        let mut used = HashSet::new();
+        used.insert("life0".to_string());
+
+        let prefix = unique_numbered_prefix("life", &used, 1);
+
+        assert_eq!(prefix, "life1");
+    }
+
+    #[test]
+    fn used_names_counts_elided_lifetime_positions() {
+        let sig: Signature = parse_quote!(fn f(&self, x: &i32));
+
+        let mut used = UsedNames::default();
+        used.visit_signature(&sig);
+
+        assert_eq!(used.elided_lifetime_positions, 2);
+    }
+
+    // `'_` is just as much a "pick a fresh lifetime" signal to
+    // `CollectLifetimes` as full elision, so it must be counted the same way
+    // here, not dropped on the floor as an already-named lifetime.
+    #[test]
+    fn used_names_counts_explicit_anonymous_lifetime_positions() {
+        let sig: Signature = parse_quote!(fn f(&'_ self, x: &'_ i32));
+
+        let mut used = UsedNames::default();
+        used.visit_signature(&sig);
+
+        assert_eq!(used.elided_lifetime_positions, 2);
+    }
+
+    // Regression test for the `Self:` where-clause bug: supertrait coverage
+    // of one marker (`Send`) must not be treated as coverage of a different,
+    // independently-requested marker (`Sync`).
+    #[test]
+    fn has_bound_checks_each_marker_independently() {
+        let supertraits: Supertraits = parse_quote!(Send);
+
+        let send: Ident = parse_quote!(Send);
+        let sync: Ident = parse_quote!(Sync);
+
+        assert!(has_bound(&supertraits, &send));
+        assert!(!has_bound(&supertraits, &sync));
+    }
+
+    fn names_for_test() -> Names {
+        Names {
+            life_prefix: "'life".to_string(),
+            impl_prefix: "'impl".to_string(),
+            async_trait_lifetime: Lifetime::new("'async_trait", Span::call_site()),
+            self_prefix: "__".to_string(),
+            ret_ident: Ident::new("__ret", Span::call_site()),
+            sentinel_ident: Ident::new("__async_trait", Span::call_site()),
+            arg_prefix: "__arg".to_string(),
+        }
+    }
+
+    // Regression test for the interaction between a method-level `?Send`
+    // opt-out (chunk0-3) and a crate-level `#[async_trait(Send + Sync)]`
+    // extra-bounds list (chunk0-4): the method that opted out of `Send`
+    // must not have it silently reintroduced via `extra_bounds`.
+    #[test]
+    fn local_method_does_not_regain_send_via_extra_bounds() {
+        let mut sig: Signature = parse_quote!(async fn f() -> i32);
+        let generics: Generics = Generics::default();
+        let context = Context::Impl {
+            impl_generics: &generics,
+        };
+        let names = names_for_test();
+        let extra_bounds = vec![
+            Ident::new("Send", Span::call_site()),
+            Ident::new("Sync", Span::call_site()),
+        ];
+        let container: syn::Path = parse_quote!(Box);
+
+        transform_sig(
+            context,
+            &mut sig,
+            false,
+            false,
+            true,
+            &names,
+            &extra_bounds,
+            &container,
+        );
+
+        let output = sig.output.to_token_stream().to_string();
+        assert!(output.contains("Sync"));
+        assert!(!output.contains("Send"));
+    }
+}